@@ -1,11 +1,17 @@
 use std::fs::File;
 use pyo3::prelude::*;
 use memmap2::Mmap;
-use std::io;
+use std::io::{self, BufRead, Read, Seek, Write};
 use noodles_fasta::{self as fasta, fai};
 use noodles_fasta::fai::Record;
 use noodles_core::region::Region;
 use std::path::{Path};
+use std::collections::HashMap;
+use rayon::prelude::*;
+use noodles_bgzf as bgzf;
+use noodles_vcf as vcf;
+use noodles_fastq as fastq;
+use numpy::{PyArray1, PyArray2, PyArray3};
 
 // Expose the Record struct so we can package it nicely in Python.
 #[pyclass]
@@ -71,6 +77,80 @@ impl From<&fai::Record> for PyRecord {
     }
 }
 
+// Complement a single IUPAC base, preserving case for soft-masked (lowercase) input.
+// Bytes outside the IUPAC alphabet are passed through unchanged rather than erroring,
+// so malformed references don't panic mid-batch.
+fn complement_base(base: u8) -> u8 {
+    match base {
+        b'A' => b'T', b'T' => b'A', b'C' => b'G', b'G' => b'C', b'U' => b'A',
+        b'R' => b'Y', b'Y' => b'R', b'S' => b'S', b'W' => b'W', b'K' => b'M', b'M' => b'K',
+        b'B' => b'V', b'V' => b'B', b'D' => b'H', b'H' => b'D', b'N' => b'N',
+        b'a' => b't', b't' => b'a', b'c' => b'g', b'g' => b'c', b'u' => b'a',
+        b'r' => b'y', b'y' => b'r', b's' => b's', b'w' => b'w', b'k' => b'm', b'm' => b'k',
+        b'b' => b'v', b'v' => b'b', b'd' => b'h', b'h' => b'd', b'n' => b'n',
+        other => other,
+    }
+}
+
+fn reverse_complement_seq(bases: &[u8]) -> Vec<u8> {
+    bases.iter().rev().map(|&base| complement_base(base)).collect()
+}
+
+// Regions can request the minus strand with a trailing ":+"/":-" token, e.g.
+// "chr1:1-1000:-". Strips the token if present and reports whether it asked for
+// the reverse strand.
+fn split_region_strand(region_str: &str) -> (&str, bool) {
+    if let Some(stripped) = region_str.strip_suffix(":-") {
+        (stripped, true)
+    } else if let Some(stripped) = region_str.strip_suffix(":+") {
+        (stripped, false)
+    } else {
+        (region_str, false)
+    }
+}
+
+#[cfg(test)]
+mod strand_and_complement_tests {
+    use super::*;
+
+    #[test]
+    fn complement_base_covers_iupac_ambiguity_codes_both_cases() {
+        assert_eq!(complement_base(b'A'), b'T');
+        assert_eq!(complement_base(b'G'), b'C');
+        assert_eq!(complement_base(b'R'), b'Y');
+        assert_eq!(complement_base(b'r'), b'y');
+        assert_eq!(complement_base(b'N'), b'N');
+        assert_eq!(complement_base(b'n'), b'n');
+    }
+
+    #[test]
+    fn complement_base_leaves_unrecognized_bytes_unchanged() {
+        assert_eq!(complement_base(b'-'), b'-');
+    }
+
+    #[test]
+    fn reverse_complement_seq_reverses_order_and_complements_each_base() {
+        assert_eq!(reverse_complement_seq(b"ACGTn"), b"nACGT".to_vec());
+        assert_eq!(reverse_complement_seq(b"ACGT"), b"ACGT".to_vec());
+        assert_eq!(reverse_complement_seq(b"AACG"), b"CGTT".to_vec());
+    }
+
+    #[test]
+    fn split_region_strand_strips_minus_token_and_reports_reverse() {
+        assert_eq!(split_region_strand("chr1:1-1000:-"), ("chr1:1-1000", true));
+    }
+
+    #[test]
+    fn split_region_strand_strips_plus_token_and_reports_forward() {
+        assert_eq!(split_region_strand("chr1:1-1000:+"), ("chr1:1-1000", false));
+    }
+
+    #[test]
+    fn split_region_strand_leaves_untagged_region_unchanged() {
+        assert_eq!(split_region_strand("chr1:1-1000"), ("chr1:1-1000", false));
+    }
+}
+
 #[pyclass]
 struct _IndexedFastaReader {
     reader: fasta::io::IndexedReader<fasta::io::BufReader<File>>,
@@ -110,19 +190,23 @@ impl _IndexedFastaReader {
         Ok(_IndexedFastaReader { reader })
     }
 
-    fn query_region(&mut self, region_str: &str) -> PyResult<String> {
+    #[pyo3(signature = (region_str, reverse_complement=false))]
+    fn query_region(&mut self, region_str: &str, reverse_complement: bool) -> PyResult<String> {
+        let (region_str, strand_reverse) = split_region_strand(region_str);
         let region: noodles_core::region::Region = region_str.parse()
             .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid region: {}", e)))?;
 
         let query_result = self.reader.query(&region)
             .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to query region: {}", e)))?;
 
-        
-        Ok(
-            String::from_utf8_lossy(
-                query_result.sequence().as_ref()
-            ).to_string()
-        )
+        let bases = query_result.sequence().as_ref();
+        let bases = if reverse_complement || strand_reverse {
+            reverse_complement_seq(bases)
+        } else {
+            bases.to_vec()
+        };
+
+        Ok(String::from_utf8_lossy(&bases).to_string())
     }
 
     fn records(&self) -> Vec<PyRecord> {
@@ -167,49 +251,325 @@ fn fai_record_end_in_bytes(record: &fai::Record) -> usize {
 }
 
 
-fn read_sequence_mmap(index: &fai::Index, reader: &Mmap, region_str: &str) -> io::Result<Vec<u8>> {
+// Where the bytes for a region actually come from. Plain FASTA is read straight off
+// an mmap, which is naturally safe to share across threads; bgzip-compressed FASTA
+// (`.fa.gz`) is read through a seekable bgzf reader using virtual offsets derived
+// from the `.gzi` index. A bgzf reader is stateful (its position moves as you read),
+// so rather than share one behind a `Mutex` -- which would serialize every region
+// query in `query_regions` on that lock -- each query reopens the file and gets its
+// own reader, keeping the bgzf path just as parallel as the mmap path.
+enum Backend {
+    Mmap(Mmap),
+    Bgzf {
+        path: std::path::PathBuf,
+        gzi: bgzf::gzi::Index,
+    },
+}
+
+// Translate an uncompressed .fai byte offset into a bgzf virtual offset
+// (compressed block offset << 16 | uncompressed offset within that block) using the
+// block boundaries recorded in the .gzi index.
+fn bgzf_virtual_position(gzi: &bgzf::gzi::Index, uncompressed_offset: u64) -> io::Result<bgzf::VirtualPosition> {
+    let mut compressed_start = 0u64;
+    let mut uncompressed_start = 0u64;
+
+    for &(compressed, uncompressed) in gzi.as_ref() {
+        if uncompressed > uncompressed_offset {
+            break;
+        }
+        compressed_start = compressed;
+        uncompressed_start = uncompressed;
+    }
+
+    let within_block = uncompressed_offset - uncompressed_start;
+    bgzf::VirtualPosition::try_from((compressed_start, within_block as u16))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("invalid bgzf virtual position: {}", e)))
+}
+
+#[cfg(test)]
+mod bgzf_virtual_position_tests {
+    use super::*;
+
+    // Block 0 covers uncompressed bytes [0, 100) and starts at compressed offset 0;
+    // block 1 covers [100, 250) and starts at compressed offset 40.
+    fn gzi_fixture() -> bgzf::gzi::Index {
+        bgzf::gzi::Index::from(vec![(0, 0), (40, 100)])
+    }
+
+    #[test]
+    fn maps_the_first_byte_of_the_first_block() {
+        let position = bgzf_virtual_position(&gzi_fixture(), 0).unwrap();
+        assert_eq!(position.compressed(), 0);
+        assert_eq!(position.uncompressed(), 0);
+    }
+
+    #[test]
+    fn maps_an_offset_within_the_first_block() {
+        let position = bgzf_virtual_position(&gzi_fixture(), 50).unwrap();
+        assert_eq!(position.compressed(), 0);
+        assert_eq!(position.uncompressed(), 50);
+    }
+
+    #[test]
+    fn maps_the_first_byte_of_a_later_block() {
+        // This is the off-by-one-prone case: offset 100 is the boundary itself, and
+        // must land at the *start* of block 1 (within_block == 0), not the last byte
+        // of block 0.
+        let position = bgzf_virtual_position(&gzi_fixture(), 100).unwrap();
+        assert_eq!(position.compressed(), 40);
+        assert_eq!(position.uncompressed(), 0);
+    }
+
+    #[test]
+    fn maps_an_offset_within_a_later_block() {
+        let position = bgzf_virtual_position(&gzi_fixture(), 149).unwrap();
+        assert_eq!(position.compressed(), 40);
+        assert_eq!(position.uncompressed(), 49);
+    }
+}
+
+fn read_sequence(
+    index: &fai::Index,
+    record_map: &HashMap<String, fai::Record>,
+    backend: &Backend,
+    region_str: &str,
+) -> io::Result<Vec<u8>> {
     let region: noodles_core::region::Region = region_str.parse()
         .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid region: {}", e)))?;
-        
+
     // the string -> region transform happens on the region FromStr implementation, nice one rust!
     let start: u64 = index.query(&region)?; // byte offset for the start of this contig + sequence.
 
-    // but we actually want the parameters for this guy too...
-    let record = index.as_ref()
-            .iter()
-            .find(|record| record.name() == region.name())
-            .ok_or_else(|| {
-                io::Error::new(
-                    io::ErrorKind::InvalidInput,
-                    format!("invalid reference sequence name: {}", region.name(),),
-                )
-            })?;
-
-    if let Some(len) = region_length(&region){
-        // Mental math, if we have the region length, we can compute the end of the record by adding the newline characters
-       
-
-        let mut result = vec![];
-        let _ = read_sequence_limit(
-            reader,
-            start as usize,
-            len,
-            record.line_bases() as usize,
-            record.line_width() as usize,
-            fai_record_end_in_bytes(record),
-            &mut result,
-        );
-        return Ok(result);
-    } 
-    else {
+    // O(1) lookup instead of scanning every record in the index on each call.
+    let record = record_map
+        .get(region.name())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid reference sequence name: {}", region.name(),),
+            )
+        })?;
+
+    // `region_length` is `end - start`, but the region interval is 1-based and
+    // inclusive of `end`, so one more base is read.
+    let len = region_length(&region)
+        .map(|len| len + 1)
         // not really an IO error but whatever.
-        return io::Result::Err(io::Error::new(io::ErrorKind::InvalidInput, "Invalid region"));
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Invalid region"))?;
+
+    // `fai_record_end_in_bytes` is relative to the start of this record, but `start`
+    // (and the position the Mmap/Bgzf backends read against) is an absolute file
+    // offset, so it has to be anchored at `record.offset()` -- otherwise every contig
+    // after the first has a `record_end` that's already behind its own start.
+    let record_end = record.offset() as usize + fai_record_end_in_bytes(record);
+    let mut result = vec![];
+
+    match backend {
+        Backend::Mmap(mmap) => {
+            read_sequence_limit(
+                mmap,
+                start as usize,
+                len,
+                record.line_bases() as usize,
+                record.line_width() as usize,
+                record_end,
+                &mut result,
+            )?;
+        }
+        Backend::Bgzf { path, gzi } => {
+            let mut reader = bgzf::Reader::new(File::open(path)?);
+            let position = bgzf_virtual_position(gzi, start)?;
+            reader.seek(position)?;
+            read_sequence_bgzf(
+                &mut reader,
+                start as usize,
+                len,
+                record.line_bases() as usize,
+                record.line_width() as usize,
+                record_end,
+                &mut result,
+            )?;
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod read_sequence_tests {
+    use super::*;
+
+    // Two contigs, each wrapped at 10 bases/line, so `chr2`'s records start well
+    // after byte 0 -- the exact shape that exposed the unanchored `record_end` bug,
+    // since `chr2`'s absolute start position is already past `chr1`'s record-relative
+    // end.
+    const MULTI_CONTIG_FASTA: &str =
+        ">chr1\nAAAAAAAAAA\nAAAAAAAAAA\nAAAAA\n>chr2\nACGTACGTAC\nGTACGTACGT\nACGTA\n";
+
+    fn write_fixture(path: &std::path::Path, content: &str) {
+        let mut file = File::create(path).unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+    }
+
+    fn record_map_for(index: &fai::Index) -> HashMap<String, fai::Record> {
+        index
+            .as_ref()
+            .iter()
+            .map(|record| (String::from_utf8_lossy(record.name()).to_string(), record.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn read_sequence_queries_a_contig_after_the_first_via_mmap() {
+        let fasta_path = std::env::temp_dir().join(format!("bionemo_multi_contig_mmap_{}.fa", std::process::id()));
+        write_fixture(&fasta_path, MULTI_CONTIG_FASTA);
+
+        let index = fasta::io::index(&fasta_path).unwrap();
+        let record_map = record_map_for(&index);
+
+        let fd = File::open(&fasta_path).unwrap();
+        let mmap = unsafe { memmap2::MmapOptions::new().map(&fd) }.unwrap();
+        let backend = Backend::Mmap(mmap);
+
+        let bases = read_sequence(&index, &record_map, &backend, "chr2:1-25").unwrap();
+        assert_eq!(bases, b"ACGTACGTACGTACGTACGTACGTA".to_vec());
+
+        let _ = std::fs::remove_file(&fasta_path);
+    }
+
+    #[test]
+    fn read_sequence_queries_a_contig_after_the_first_via_bgzf() {
+        let fasta_path = std::env::temp_dir().join(format!("bionemo_multi_contig_bgzf_{}.fa", std::process::id()));
+        let gz_path = std::env::temp_dir().join(format!("bionemo_multi_contig_bgzf_{}.fa.gz", std::process::id()));
+        write_fixture(&fasta_path, MULTI_CONTIG_FASTA);
+
+        {
+            let mut writer = bgzf::Writer::new(File::create(&gz_path).unwrap());
+            writer.write_all(MULTI_CONTIG_FASTA.as_bytes()).unwrap();
+            writer.try_finish().unwrap();
+        }
+
+        // The .fai index is built from the plain (uncompressed) bytes, same as
+        // `IndexedMmapFastaReader::new` does when a caller supplies one alongside a
+        // .gz reference -- the uncompressed offsets it records are exactly what
+        // `bgzf_virtual_position` translates into compressed virtual offsets.
+        let index = fasta::io::index(&fasta_path).unwrap();
+        let record_map = record_map_for(&index);
+        let gzi = bgzf::gzi::index(&gz_path).unwrap();
+        let backend = Backend::Bgzf { path: gz_path.clone(), gzi };
+
+        let bases = read_sequence(&index, &record_map, &backend, "chr2:11-20").unwrap();
+        assert_eq!(bases, b"GTACGTACGT".to_vec());
+
+        let _ = std::fs::remove_file(&fasta_path);
+        let _ = std::fs::remove_file(&gz_path);
+    }
+}
+
+// Parses an alphabet spec like "A,C,G,T,N" into the bytes defining the base -> token
+// index map (index into the list == token id).
+fn parse_alphabet(alphabet: &str) -> Vec<u8> {
+    alphabet
+        .split(',')
+        .filter_map(|token| token.trim().bytes().next())
+        .collect()
+}
+
+// Builds a 256-entry base -> token lookup table so encoding is a single array index
+// per base, with lowercase (soft-masked) bases folding to the same token as their
+// uppercase form. Bases outside `alphabet` map to `unknown_index`.
+fn build_token_table(alphabet: &[u8], unknown_index: u8) -> [u8; 256] {
+    let mut table = [unknown_index; 256];
+    for (token, &base) in alphabet.iter().enumerate() {
+        table[base.to_ascii_uppercase() as usize] = token as u8;
+        table[base.to_ascii_lowercase() as usize] = token as u8;
+    }
+    table
+}
+
+fn encode_tokens(bases: &[u8], table: &[u8; 256]) -> Vec<u8> {
+    bases.iter().map(|&base| table[base as usize]).collect()
+}
+
+// One-hot encodes `bases` into a flat, row-major (L, K) buffer. A token equal to or
+// past `k` (the default unknown sentinel, when the caller didn't configure an explicit
+// unknown_index within the alphabet) leaves its row all-zero.
+fn encode_onehot(bases: &[u8], table: &[u8; 256], k: usize) -> Vec<f32> {
+    let mut out = vec![0f32; bases.len() * k];
+    for (i, &base) in bases.iter().enumerate() {
+        let token = table[base as usize] as usize;
+        if token < k {
+            out[i * k + token] = 1.0;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod encoding_tests {
+    use super::*;
+
+    #[test]
+    fn parse_alphabet_splits_on_commas_and_trims_whitespace() {
+        assert_eq!(parse_alphabet("A,C,G,T,N"), vec![b'A', b'C', b'G', b'T', b'N']);
+        assert_eq!(parse_alphabet("A, C, G, T"), vec![b'A', b'C', b'G', b'T']);
+    }
+
+    #[test]
+    fn build_token_table_folds_lowercase_soft_masked_bases_to_the_same_token() {
+        let table = build_token_table(&[b'A', b'C', b'G', b'T'], 4);
+        assert_eq!(table[b'A' as usize], 0);
+        assert_eq!(table[b'a' as usize], 0);
+        assert_eq!(table[b'T' as usize], 3);
+        assert_eq!(table[b't' as usize], 3);
+        // Anything outside the alphabet falls back to the unknown index.
+        assert_eq!(table[b'N' as usize], 4);
+    }
+
+    #[test]
+    fn encode_tokens_maps_each_base_through_the_table() {
+        let table = build_token_table(&[b'A', b'C', b'G', b'T'], 4);
+        assert_eq!(encode_tokens(b"ACgtN", &table), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn encode_onehot_sets_a_single_column_per_known_base() {
+        let table = build_token_table(&[b'A', b'C', b'G', b'T'], 4);
+        let onehot = encode_onehot(b"AC", &table, 4);
+        assert_eq!(onehot, vec![1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn encode_onehot_leaves_unknown_bases_all_zero_when_unknown_index_is_out_of_range() {
+        let table = build_token_table(&[b'A', b'C', b'G', b'T'], 4);
+        let onehot = encode_onehot(b"N", &table, 4);
+        assert_eq!(onehot, vec![0.0, 0.0, 0.0, 0.0]);
     }
 }
 
 #[pyclass]
 struct IndexedMmapFastaReader {
-    mmap_reader: memmap2::Mmap,
+    backend: Backend,
+    index: fai::Index,
+    // name -> record, built once at construction so every query is an O(1) lookup
+    // instead of a linear scan over the whole index.
+    record_map: HashMap<String, fai::Record>,
+}
+
+impl IndexedMmapFastaReader {
+    // Shared by query_region/query_regions: strips a trailing strand token from
+    // `region_str`, extracts the bases, and reverse-complements them if either the
+    // token or the explicit flag asked for the minus strand.
+    fn extract_region(&self, region_str: &str, reverse_complement: bool) -> io::Result<Vec<u8>> {
+        let (region_str, strand_reverse) = split_region_strand(region_str);
+        let bases = read_sequence(&self.index, &self.record_map, &self.backend, region_str)?;
+
+        if reverse_complement || strand_reverse {
+            Ok(reverse_complement_seq(&bases))
+        } else {
+            Ok(bases)
+        }
+    }
 }
 
 #[pymethods]
@@ -220,8 +580,24 @@ impl IndexedMmapFastaReader {
         let fai_path = Path::new(&fai_path);  // Convert back to a Path
         let fasta_path = Path::new(fasta_path);
 
+        // Has to be known before we decide how (or whether) to build a missing .fai:
+        // noodles' plain fai indexer reads raw, uncompressed bytes and has no bgzf
+        // awareness, so running it straight over compressed input either errors
+        // confusingly or produces a bogus index.
+        let is_bgzipped = fasta_path.extension().and_then(|ext| ext.to_str()) == Some("gz");
+
         // Check if the .fai index file exists; if not, create it.
-        if !fai_path.exists() {
+        let index = if fai_path.exists() {
+            fai::read(fai_path)
+                .map_err(|e| pyo3::exceptions::PyIOError::new_err(format!("Failed to read .fai index: {}", e)))?
+        } else if is_bgzipped {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "No .fai index found alongside compressed input '{}'; a .fai must be \
+                 supplied for bgzipped FASTA since it can't be built from the raw \
+                 compressed bytes",
+                fasta_path.display(),
+            )));
+        } else {
             // Generate the index by reading the FASTA file
             let index = fasta::io::index(fasta_path)
                 .map_err(|e| pyo3::exceptions::PyIOError::new_err(format!("Failed to create index: {}", e)))?;
@@ -233,13 +609,157 @@ impl IndexedMmapFastaReader {
             let mut writer = fai::Writer::new(fai_file);
             writer.write_index(&index)
                 .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to write .fai index: {}", e)))?;
+
+            index
+        };
+
+        let backend = if is_bgzipped {
+            let gzi_path = fasta_path.to_string_lossy().to_string() + ".gzi";
+            let gzi_path = Path::new(&gzi_path);
+
+            let gzi = if gzi_path.exists() {
+                bgzf::gzi::read(&gzi_path)
+                    .map_err(|e| pyo3::exceptions::PyIOError::new_err(format!("Failed to read .gzi index: {}", e)))?
+            } else {
+                let gzi = bgzf::gzi::index(fasta_path)
+                    .map_err(|e| pyo3::exceptions::PyIOError::new_err(format!("Failed to build .gzi index: {}", e)))?;
+
+                bgzf::gzi::write(&gzi_path, &gzi)
+                    .map_err(|e| pyo3::exceptions::PyIOError::new_err(format!("Failed to write .gzi index: {}", e)))?;
+
+                gzi
+            };
+
+            // Open once up front just to fail fast on a bad path; each query reopens
+            // its own reader (see the comment on `Backend`).
+            File::open(fasta_path)?;
+            Backend::Bgzf { path: fasta_path.to_path_buf(), gzi }
+        } else {
+            let fd = File::open(fasta_path)?;
+            let mmap = unsafe { memmap2::MmapOptions::new().map(&fd) }?;
+            Backend::Mmap(mmap)
+        };
+
+        let record_map = index
+            .as_ref()
+            .iter()
+            .map(|record| (String::from_utf8_lossy(record.name()).to_string(), record.clone()))
+            .collect();
+
+        Ok(IndexedMmapFastaReader { backend, index, record_map })
+    }
+
+    /// Extract the sequence for a single region, e.g. "chr1:1-1000". Pass
+    /// `reverse_complement=True`, or append a ":-" strand token to `region`, to get
+    /// the reverse complement of the extracted bases (soft-masking is preserved).
+    #[pyo3(signature = (region, reverse_complement=false))]
+    fn query_region(&self, region: &str, reverse_complement: bool) -> PyResult<String> {
+        let bases = self.extract_region(region, reverse_complement)
+            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to query region '{}': {}", region, e)))?;
+
+        Ok(String::from_utf8_lossy(&bases).to_string())
+    }
+
+    /// Extract many regions at once, parallelized across regions with rayon.
+    ///
+    /// The mmap is `Send + Sync` and the reader holds no mutable state, so this is
+    /// safe to fan out across threads, which is much faster than querying one at a
+    /// time from Python for dataloaders that need thousands of windows per step.
+    #[pyo3(signature = (regions, reverse_complement=false))]
+    fn query_regions(&self, regions: Vec<String>, reverse_complement: bool) -> PyResult<Vec<String>> {
+        regions
+            .par_iter()
+            .map(|region| {
+                self.extract_region(region, reverse_complement)
+                    .map(|bases| String::from_utf8_lossy(&bases).to_string())
+                    .map_err(|e| {
+                        pyo3::exceptions::PyRuntimeError::new_err(format!(
+                            "Failed to query region '{}': {}",
+                            region, e
+                        ))
+                    })
+            })
+            .collect()
+    }
+
+    /// Like `query_region`, but returns an `ndarray` of `u8` token ids instead of a
+    /// `str`, so an ML training loop can hand it straight to a framework tensor
+    /// without a decode + re-encode round trip. `alphabet` is a comma-separated
+    /// base list (default DNA `A,C,G,T,N`); bases not in it map to `unknown_index`
+    /// (default: one past the last valid token).
+    #[pyo3(signature = (region, alphabet="A,C,G,T,N".to_string(), unknown_index=None))]
+    fn query_region_tokens<'py>(
+        &self,
+        py: Python<'py>,
+        region: &str,
+        alphabet: String,
+        unknown_index: Option<u8>,
+    ) -> PyResult<&'py PyArray1<u8>> {
+        let bases = self.extract_region(region, false)
+            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to query region '{}': {}", region, e)))?;
+
+        let alphabet = parse_alphabet(&alphabet);
+        let table = build_token_table(&alphabet, unknown_index.unwrap_or(alphabet.len() as u8));
+
+        Ok(PyArray1::from_vec(py, encode_tokens(&bases, &table)))
+    }
+
+    /// Like `query_region_tokens`, but returns an `(L, K)` one-hot `f32` array. Bases
+    /// outside `alphabet` get an all-zero row unless `unknown_index` points at a
+    /// column within the alphabet.
+    #[pyo3(signature = (region, alphabet="A,C,G,T,N".to_string(), unknown_index=None))]
+    fn query_region_onehot<'py>(
+        &self,
+        py: Python<'py>,
+        region: &str,
+        alphabet: String,
+        unknown_index: Option<u8>,
+    ) -> PyResult<&'py PyArray2<f32>> {
+        let bases = self.extract_region(region, false)
+            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to query region '{}': {}", region, e)))?;
+
+        let alphabet = parse_alphabet(&alphabet);
+        let k = alphabet.len();
+        let table = build_token_table(&alphabet, unknown_index.unwrap_or(k as u8));
+
+        let flat = PyArray1::from_vec(py, encode_onehot(&bases, &table, k));
+        flat.reshape([bases.len(), k])
+    }
+
+    /// Batched `query_region_onehot`, parallelized across regions with rayon and
+    /// stacked into a single contiguous `(N, L, K)` array. All regions must share the
+    /// same length `L` since the result is one contiguous array rather than a list.
+    #[pyo3(signature = (regions, alphabet="A,C,G,T,N".to_string(), unknown_index=None))]
+    fn query_regions_onehot<'py>(
+        &self,
+        py: Python<'py>,
+        regions: Vec<String>,
+        alphabet: String,
+        unknown_index: Option<u8>,
+    ) -> PyResult<&'py PyArray3<f32>> {
+        let alphabet = parse_alphabet(&alphabet);
+        let k = alphabet.len();
+        let table = build_token_table(&alphabet, unknown_index.unwrap_or(k as u8));
+
+        let encoded: Vec<(usize, Vec<f32>)> = regions
+            .par_iter()
+            .map(|region| {
+                let bases = self.extract_region(region, false).map_err(|e| {
+                    pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to query region '{}': {}", region, e))
+                })?;
+                Ok((bases.len(), encode_onehot(&bases, &table, k)))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let length = encoded.first().map(|(len, _)| *len).unwrap_or(0);
+        if encoded.iter().any(|(len, _)| *len != length) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "query_regions_onehot requires all regions to share the same length",
+            ));
         }
 
-        // TODO this is where we load our shit
-        let fd = File::open(fasta_path)?;
-        let mmap_reader = unsafe { memmap2::MmapOptions::new().map(&fd) }?;
-        
-        Ok(IndexedMmapFastaReader { mmap_reader })
+        let flat: Vec<f32> = encoded.into_iter().flat_map(|(_, row)| row).collect();
+        PyArray1::from_vec(py, flat).reshape([regions.len(), length, k])
     }
 }
 
@@ -298,10 +818,663 @@ fn read_sequence_limit(
     Ok(read_count)
 }
 
+// Same line_bases/line_width-structured reading as `read_sequence_limit`, but over a
+// seekable bgzf reader already positioned at `start`'s virtual offset instead of a slice
+// of an mmap. The reader is consumed sequentially line-by-line since bgzf decompresses
+// forward from the seeked block.
+fn read_sequence_bgzf<R: io::Read>(
+    reader: &mut R,
+    start: usize,
+    max_bases: usize,
+    line_bases: usize,
+    line_width: usize,
+    record_end: usize,
+    buf: &mut Vec<u8>,
+) -> io::Result<usize> {
+    let mut read_count = 0;
+    let mut position = start;
+    let junk_offset = line_width - line_bases;
+    let mut line = vec![0u8; line_bases];
+
+    while read_count < max_bases && position < record_end {
+        let bases_remaining_in_record = record_end - position;
+        let bases_this_line = line_bases
+            .min(bases_remaining_in_record)
+            .min(max_bases - read_count);
+
+        reader.read_exact(&mut line[..bases_this_line])?;
+        buf.extend_from_slice(&line[..bases_this_line]);
+
+        read_count += bases_this_line;
+        position += bases_this_line;
+
+        // If we consumed a full line (and more of the record follows), skip past the
+        // newline/carriage-return padding before the next line.
+        if bases_this_line == line_bases && position < record_end {
+            let mut junk = vec![0u8; junk_offset];
+            reader.read_exact(&mut junk)?;
+            position += junk_offset;
+        }
+    }
+
+    Ok(read_count)
+}
+
+
+// A single substitution to splice into the reference buffer: the 0-based offset into
+// the *queried region* (not the whole contig) where it starts, the reference bases it
+// replaces, and the bases to put in their place. `ref_bases.len() != replacement.len()`
+// is how indels shift the rest of the buffer.
+struct VariantEdit {
+    region_offset: usize,
+    ref_bases: Vec<u8>,
+    replacement: Vec<u8>,
+}
+
+// How to resolve a heterozygous SNV: keep the reference allele, take the ALT allele, or
+// emit the IUPAC ambiguity code representing both.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum HetPolicy {
+    Ref,
+    Alt,
+    Iupac,
+}
+
+impl HetPolicy {
+    fn parse(value: &str) -> PyResult<Self> {
+        match value {
+            "ref" => Ok(HetPolicy::Ref),
+            "alt" => Ok(HetPolicy::Alt),
+            "iupac" => Ok(HetPolicy::Iupac),
+            other => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Invalid het_policy '{}': expected 'ref', 'alt', or 'iupac'",
+                other
+            ))),
+        }
+    }
+}
+
+// Which genotypes get applied to the consensus sequence.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum GenotypeFilter {
+    All,
+    HomAlt,
+    Het,
+}
+
+impl GenotypeFilter {
+    fn parse(value: &str) -> PyResult<Self> {
+        match value {
+            "all" => Ok(GenotypeFilter::All),
+            "hom-alt" => Ok(GenotypeFilter::HomAlt),
+            "het" => Ok(GenotypeFilter::Het),
+            other => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Invalid genotype_filter '{}': expected 'all', 'hom-alt', or 'het'",
+                other
+            ))),
+        }
+    }
+}
+
+// The IUPAC ambiguity code for two (possibly identical) bases, used when a
+// heterozygous SNV should be emitted as ambiguity rather than picking one allele.
+fn iupac_ambiguity_code(a: u8, b: u8) -> u8 {
+    let mut bases = [a.to_ascii_uppercase(), b.to_ascii_uppercase()];
+    bases.sort_unstable();
+    match bases {
+        [b'A', b'A'] => b'A', [b'C', b'C'] => b'C', [b'G', b'G'] => b'G', [b'T', b'T'] => b'T',
+        [b'A', b'G'] => b'R', [b'C', b'T'] => b'Y', [b'C', b'G'] => b'S', [b'A', b'T'] => b'W',
+        [b'G', b'T'] => b'K', [b'A', b'C'] => b'M',
+        _ => b'N',
+    }
+}
+
+// Genotype allele indices parsed out of a raw VCF `GT` value such as "0/1" or "1|1".
+// Noodles' genotype field API varies by version; parsing the rendered value directly
+// keeps this independent of that.
+fn genotype_allele_indices(gt: &str) -> Vec<usize> {
+    gt.split(|c| c == '/' || c == '|')
+        .filter_map(|allele| allele.parse::<usize>().ok())
+        .collect()
+}
+
+/// Splices VCF/BCF variant calls into reference sequence windows to produce a
+/// personalized/consensus sequence, analogous to pyfaidx's `FastaVariant`.
+#[pyclass]
+struct VariantOverlayFastaReader {
+    fasta: IndexedMmapFastaReader,
+    vcf_path: std::path::PathBuf,
+    sample: Option<String>,
+    genotype_filter: GenotypeFilter,
+    het_policy: HetPolicy,
+    iupac_for_het_snv: bool,
+}
+
+#[pymethods]
+impl VariantOverlayFastaReader {
+    #[new]
+    #[pyo3(signature = (fasta_path, variant_path, sample=None, genotype_filter="all".to_string(), het_policy="ref".to_string(), iupac_for_het_snv=false))]
+    fn new(
+        fasta_path: &str,
+        variant_path: &str,
+        sample: Option<String>,
+        genotype_filter: String,
+        het_policy: String,
+        iupac_for_het_snv: bool,
+    ) -> PyResult<Self> {
+        Ok(VariantOverlayFastaReader {
+            fasta: IndexedMmapFastaReader::new(fasta_path)?,
+            vcf_path: std::path::PathBuf::from(variant_path),
+            sample,
+            genotype_filter: GenotypeFilter::parse(&genotype_filter)?,
+            het_policy: HetPolicy::parse(&het_policy)?,
+            iupac_for_het_snv,
+        })
+    }
+
+    /// Extract `region` from the reference and splice in overlapping variant calls,
+    /// applied left-to-right with a cumulative shift so indels keep later
+    /// substitutions aligned. Positions outside the region are ignored, and a variant
+    /// that overlaps one already applied is skipped rather than clobbering it.
+    fn query_region(&self, region: &str) -> PyResult<String> {
+        let (region, _strand_reverse) = split_region_strand(region);
+        let parsed_region: Region = region.parse()
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid region: {}", e)))?;
+
+        let reference = self.fasta.extract_region(region, false)
+            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to query region '{}': {}", region, e)))?;
+
+        let edits = self.collect_edits(&parsed_region)?;
+        Ok(String::from_utf8_lossy(&apply_edits(&reference, &edits)).to_string())
+    }
+}
+
+impl VariantOverlayFastaReader {
+    fn collect_edits(&self, region: &Region) -> PyResult<Vec<VariantEdit>> {
+        let interval = region.interval();
+        let region_start = interval.start().map(|p| p.get() as u64).unwrap_or(1);
+        let region_end = interval.end().map(|p| p.get() as u64);
+
+        let mut reader = vcf::io::reader::Builder::default()
+            .build_from_path(&self.vcf_path)
+            .map_err(|e| pyo3::exceptions::PyIOError::new_err(format!("Failed to open variant file: {}", e)))?;
+
+        let header = reader.read_header()
+            .map_err(|e| pyo3::exceptions::PyIOError::new_err(format!("Failed to read variant header: {}", e)))?;
+
+        let sample_index = match &self.sample {
+            Some(name) => header
+                .sample_names()
+                .iter()
+                .position(|n| n == name)
+                .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(format!("Unknown sample '{}'", name)))?,
+            None => 0,
+        };
+
+        let mut edits = Vec::new();
+
+        for result in reader.records() {
+            let record = result.map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to read variant record: {}", e)))?;
+
+            if record.chromosome().to_string() != region.name() {
+                continue;
+            }
+
+            let pos = record.position().get() as u64;
+            if pos < region_start || region_end.map_or(false, |end| pos > end) {
+                // Positions outside the region interval are ignored.
+                continue;
+            }
+
+            let gt = record
+                .genotypes()
+                .get(sample_index)
+                .and_then(|genotype| genotype.get("GT"))
+                .map(|value| value.to_string());
+
+            let Some(gt) = gt else { continue };
+            let alleles = genotype_allele_indices(&gt);
+            if alleles.is_empty() {
+                continue;
+            }
+
+            let is_hom_alt = alleles.iter().all(|&a| a > 0) && alleles.windows(2).all(|w| w[0] == w[1]);
+            let is_het = alleles.iter().any(|&a| a == 0) && alleles.iter().any(|&a| a > 0)
+                || (alleles.iter().all(|&a| a > 0) && alleles.windows(2).any(|w| w[0] != w[1]));
+
+            let applies = match self.genotype_filter {
+                GenotypeFilter::All => is_hom_alt || is_het,
+                GenotypeFilter::HomAlt => is_hom_alt,
+                GenotypeFilter::Het => is_het,
+            };
+            if !applies {
+                continue;
+            }
+
+            let ref_bases = record.reference_bases().to_string().into_bytes();
+            let alt_alleles: Vec<String> = record.alternate_bases().iter().map(|a| a.to_string()).collect();
+
+            let replacement = if is_het && !is_hom_alt {
+                self.resolve_het(&ref_bases, &alt_alleles, &alleles)
+            } else {
+                // Homozygous-ALT: apply the (single, since hom) called ALT allele.
+                let alt_index = alleles.iter().find(|&&a| a > 0).copied().unwrap_or(1) - 1;
+                alt_alleles
+                    .get(alt_index)
+                    .map(|allele| allele.as_bytes().to_vec())
+                    .unwrap_or_else(|| ref_bases.clone())
+            };
+
+            edits.push(VariantEdit {
+                region_offset: (pos - region_start) as usize,
+                ref_bases,
+                replacement,
+            });
+        }
+
+        // Apply left-to-right; overlapping/clobbering variants are rejected
+        // deterministically by keeping whichever sorts first and dropping the rest.
+        edits.sort_by_key(|edit| edit.region_offset);
+        let mut accepted: Vec<VariantEdit> = Vec::with_capacity(edits.len());
+        let mut next_free_offset = 0usize;
+        for edit in edits {
+            if edit.region_offset < next_free_offset {
+                continue;
+            }
+            next_free_offset = edit.region_offset + edit.ref_bases.len();
+            accepted.push(edit);
+        }
+
+        Ok(accepted)
+    }
+
+    fn resolve_het(&self, ref_bases: &[u8], alt_alleles: &[String], alleles: &[usize]) -> Vec<u8> {
+        resolve_het_alleles(ref_bases, alt_alleles, alleles, self.het_policy, self.iupac_for_het_snv)
+    }
+}
+
+// The actual het-resolution logic, free of `self` so it's exercisable directly in
+// unit tests without constructing a full `VariantOverlayFastaReader`.
+fn resolve_het_alleles(
+    ref_bases: &[u8],
+    alt_alleles: &[String],
+    alleles: &[usize],
+    het_policy: HetPolicy,
+    iupac_for_het_snv: bool,
+) -> Vec<u8> {
+    let has_ref_allele = alleles.iter().any(|&a| a == 0);
+
+    if has_ref_allele {
+        let alt = alleles.iter().find(|&&a| a > 0).and_then(|&a| alt_alleles.get(a - 1));
+
+        if let Some(alt) = alt {
+            if ref_bases.len() == 1 && alt.len() == 1 {
+                if iupac_for_het_snv || het_policy == HetPolicy::Iupac {
+                    return vec![iupac_ambiguity_code(ref_bases[0], alt.as_bytes()[0])];
+                }
+                return match het_policy {
+                    HetPolicy::Alt => alt.as_bytes().to_vec(),
+                    HetPolicy::Ref | HetPolicy::Iupac => ref_bases.to_vec(),
+                };
+            }
+        }
+
+        // Heterozygous indel: IUPAC ambiguity codes don't apply, so fall back to
+        // the configured ref/alt preference (defaulting to the reference allele).
+        return match het_policy {
+            HetPolicy::Alt => alt.map(|a| a.as_bytes().to_vec()).unwrap_or_else(|| ref_bases.to_vec()),
+            HetPolicy::Ref | HetPolicy::Iupac => ref_bases.to_vec(),
+        };
+    }
+
+    // Compound het, e.g. "1/2": neither called allele is the reference, so the
+    // IUPAC code / ref-vs-alt choice must be built from the two called ALT
+    // alleles, not from `ref_bases`.
+    let mut called_alts = alleles.iter().copied().filter(|&a| a > 0);
+    let first_alt = called_alts.next().and_then(|a| alt_alleles.get(a - 1));
+    let second_alt = called_alts.next().and_then(|a| alt_alleles.get(a - 1)).or(first_alt);
+
+    match (first_alt, second_alt) {
+        (Some(first_alt), Some(second_alt)) if first_alt.len() == 1 && second_alt.len() == 1 => {
+            if iupac_for_het_snv || het_policy == HetPolicy::Iupac {
+                vec![iupac_ambiguity_code(first_alt.as_bytes()[0], second_alt.as_bytes()[0])]
+            } else {
+                match het_policy {
+                    // There's no reference allele to prefer here; fall back to
+                    // the lower-numbered ALT for the "ref" policy.
+                    HetPolicy::Ref | HetPolicy::Iupac => first_alt.as_bytes().to_vec(),
+                    HetPolicy::Alt => second_alt.as_bytes().to_vec(),
+                }
+            }
+        }
+        // Compound-het indel: no single-base ambiguity code applies, so just
+        // pick an allele per the same ref/alt preference.
+        (Some(first_alt), Some(second_alt)) => match het_policy {
+            HetPolicy::Ref | HetPolicy::Iupac => first_alt.as_bytes().to_vec(),
+            HetPolicy::Alt => second_alt.as_bytes().to_vec(),
+        },
+        _ => ref_bases.to_vec(),
+    }
+}
+
+fn apply_edits(reference: &[u8], edits: &[VariantEdit]) -> Vec<u8> {
+    let mut result = Vec::with_capacity(reference.len());
+    let mut cursor = 0usize;
+
+    for edit in edits {
+        if edit.region_offset > reference.len() {
+            continue;
+        }
+        result.extend_from_slice(&reference[cursor..edit.region_offset]);
+        result.extend_from_slice(&edit.replacement);
+        cursor = (edit.region_offset + edit.ref_bases.len()).min(reference.len());
+    }
+    result.extend_from_slice(&reference[cursor..]);
+
+    result
+}
+
+#[cfg(test)]
+mod variant_overlay_tests {
+    use super::*;
+
+    fn alts(alleles: &[&str]) -> Vec<String> {
+        alleles.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn genotype_allele_indices_parses_phased_and_unphased_gt() {
+        assert_eq!(genotype_allele_indices("0/1"), vec![0, 1]);
+        assert_eq!(genotype_allele_indices("1|2"), vec![1, 2]);
+        assert_eq!(genotype_allele_indices("."), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn iupac_ambiguity_code_is_order_independent() {
+        assert_eq!(iupac_ambiguity_code(b'A', b'G'), b'R');
+        assert_eq!(iupac_ambiguity_code(b'G', b'A'), b'R');
+        assert_eq!(iupac_ambiguity_code(b'C', b'C'), b'C');
+    }
+
+    #[test]
+    fn resolve_het_ref_alt_snv_uses_iupac_code() {
+        // "0/1" with ref=A, alt=G should resolve to the A/G ambiguity code.
+        let result = resolve_het_alleles(b"A", &alts(&["G"]), &[0, 1], HetPolicy::Ref, true);
+        assert_eq!(result, vec![b'R']);
+    }
+
+    #[test]
+    fn resolve_het_ref_alt_snv_respects_ref_and_alt_policy() {
+        let ref_policy = resolve_het_alleles(b"A", &alts(&["G"]), &[0, 1], HetPolicy::Ref, false);
+        assert_eq!(ref_policy, b"A".to_vec());
+
+        let alt_policy = resolve_het_alleles(b"A", &alts(&["G"]), &[0, 1], HetPolicy::Alt, false);
+        assert_eq!(alt_policy, b"G".to_vec());
+    }
+
+    #[test]
+    fn resolve_het_compound_alt_alt_snv_uses_the_two_alt_alleles_not_the_reference() {
+        // "1/2" with ref=A, alt1=G, alt2=T: neither called allele is the reference,
+        // so the IUPAC code must come from G/T (-> K), not from A paired with G.
+        let result = resolve_het_alleles(b"A", &alts(&["G", "T"]), &[1, 2], HetPolicy::Ref, true);
+        assert_eq!(result, vec![b'K']);
+    }
+
+    #[test]
+    fn resolve_het_compound_alt_alt_snv_policy_falls_back_to_called_alleles() {
+        let result = resolve_het_alleles(b"A", &alts(&["G", "T"]), &[1, 2], HetPolicy::Alt, false);
+        // "alt" policy with no reference allele present picks the second called ALT.
+        assert_eq!(result, b"T".to_vec());
+    }
+
+    #[test]
+    fn resolve_het_indel_falls_back_to_ref_or_alt_preference() {
+        let ref_policy = resolve_het_alleles(b"A", &alts(&["AGG"]), &[0, 1], HetPolicy::Ref, false);
+        assert_eq!(ref_policy, b"A".to_vec());
+
+        let alt_policy = resolve_het_alleles(b"A", &alts(&["AGG"]), &[0, 1], HetPolicy::Alt, false);
+        assert_eq!(alt_policy, b"AGG".to_vec());
+    }
+
+    #[test]
+    fn apply_edits_splices_snv_in_place() {
+        let reference = b"AAAAAAAAAA".to_vec();
+        let edits = vec![VariantEdit { region_offset: 3, ref_bases: b"A".to_vec(), replacement: b"G".to_vec() }];
+        assert_eq!(apply_edits(&reference, &edits), b"AAAGAAAAAA".to_vec());
+    }
+
+    #[test]
+    fn apply_edits_shifts_later_positions_for_an_indel() {
+        // A 1-base deletion at offset 2 (ref "CA" -> "C") must shift where the next
+        // substitution lands relative to the *output* buffer, which this test
+        // verifies by checking the edit after it still ends up on the right base.
+        let reference = b"AACAAGAA".to_vec();
+        let edits = vec![
+            VariantEdit { region_offset: 2, ref_bases: b"CA".to_vec(), replacement: b"C".to_vec() },
+            VariantEdit { region_offset: 5, ref_bases: b"G".to_vec(), replacement: b"T".to_vec() },
+        ];
+        assert_eq!(apply_edits(&reference, &edits), b"AACATAA".to_vec());
+    }
+
+    #[test]
+    fn apply_edits_applies_the_edit_kept_by_the_overlap_pass() {
+        let reference = b"AAAAAAAA".to_vec();
+        // Overlapping edits are dropped by `collect_edits`'s acceptance pass before
+        // `apply_edits` runs; this checks only that `apply_edits` splices in the
+        // multi-base edit that pass keeps.
+        let edits = vec![VariantEdit { region_offset: 2, ref_bases: b"AA".to_vec(), replacement: b"GG".to_vec() }];
+        assert_eq!(apply_edits(&reference, &edits), b"AAGGAAAA".to_vec());
+    }
+}
+
+// One entry in a FASTQ's home-grown ".fqi" index (there's no samtools-style standard
+// for FASTQ the way there is for FASTA). `offset` is the byte position of the
+// record's "@name" header line, which is all `query` needs: it reopens the file,
+// seeks there, and re-parses the record with `noodles_fastq` so multi-line records
+// are handled exactly as noodles defines them rather than by a hand-rolled parser.
+struct FastqIndexEntry {
+    name: String,
+    offset: u64,
+    seq_length: u64,
+    // Best-effort byte offset of the quality string, for display/QC purposes only;
+    // derived from the record's total span assuming a single trailing newline.
+    qual_offset: u64,
+}
+
+fn build_fastq_index(path: &Path) -> io::Result<Vec<FastqIndexEntry>> {
+    let file = File::open(path)?;
+    let mut reader = fastq::io::Reader::new(io::BufReader::new(file));
+    let mut offset = 0u64;
+    let mut index = Vec::new();
+
+    loop {
+        let mut record = fastq::Record::default();
+        let bytes_read = reader.read_record(&mut record)?;
+        if bytes_read == 0 {
+            break;
+        }
+
+        let name = String::from_utf8_lossy(record.name()).to_string();
+        let seq_length = record.sequence().len() as u64;
+        let qual_offset = offset + bytes_read as u64 - record.quality_scores().len() as u64 - 1;
+
+        index.push(FastqIndexEntry { name, offset, seq_length, qual_offset });
+        offset += bytes_read as u64;
+    }
+
+    Ok(index)
+}
+
+fn write_fastq_index(path: &Path, index: &[FastqIndexEntry]) -> io::Result<()> {
+    let mut file = File::create(path)?;
+    for entry in index {
+        writeln!(file, "{}\t{}\t{}\t{}", entry.name, entry.offset, entry.seq_length, entry.qual_offset)?;
+    }
+    Ok(())
+}
+
+fn read_fastq_index(path: &Path) -> io::Result<Vec<FastqIndexEntry>> {
+    let reader = io::BufReader::new(File::open(path)?);
+    let mut index = Vec::new();
+
+    for line in reader.lines() {
+        let line = line?;
+        let mut fields = line.split('\t');
+
+        let invalid = || io::Error::new(io::ErrorKind::InvalidData, format!("malformed .fqi line: '{}'", line));
+        let name = fields.next().ok_or_else(invalid)?.to_string();
+        let offset = fields.next().and_then(|v| v.parse().ok()).ok_or_else(invalid)?;
+        let seq_length = fields.next().and_then(|v| v.parse().ok()).ok_or_else(invalid)?;
+        let qual_offset = fields.next().and_then(|v| v.parse().ok()).ok_or_else(invalid)?;
+
+        index.push(FastqIndexEntry { name, offset, seq_length, qual_offset });
+    }
+
+    Ok(index)
+}
+
+// Exposes a FastqIndexEntry to Python, mirroring PyRecord for the FASTA side.
+#[pyclass]
+#[derive(Clone)]
+struct PyFastqRecord {
+    name: String,
+    offset: u64,
+    seq_length: u64,
+    qual_offset: u64,
+}
+
+#[pymethods]
+impl PyFastqRecord {
+    #[getter]
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[getter]
+    fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    #[getter]
+    fn seq_length(&self) -> u64 {
+        self.seq_length
+    }
+
+    #[getter]
+    fn qual_offset(&self) -> u64 {
+        self.qual_offset
+    }
+
+    fn __str__(&self) -> String {
+        format!(
+            "PyFastqRecord(name={}, offset={}, seq_length={}, qual_offset={})",
+            self.name, self.offset, self.seq_length, self.qual_offset
+        )
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<PyFastqRecord name='{}' offset={} seq_length={} qual_offset={}>",
+            self.name, self.offset, self.seq_length, self.qual_offset
+        )
+    }
+}
+
+impl From<&FastqIndexEntry> for PyFastqRecord {
+    fn from(entry: &FastqIndexEntry) -> Self {
+        Self {
+            name: entry.name.clone(),
+            offset: entry.offset,
+            seq_length: entry.seq_length,
+            qual_offset: entry.qual_offset,
+        }
+    }
+}
+
+/// Random-access reader for FASTQ, paralleling the indexed FASTA readers: builds a
+/// name -> byte offset index (persisted alongside the file) so individual reads and
+/// their quality strings can be pulled out by name without scanning the whole file.
+#[pyclass]
+struct IndexedFastqReader {
+    path: std::path::PathBuf,
+    index: Vec<FastqIndexEntry>,
+    by_name: HashMap<String, usize>,
+}
+
+#[pymethods]
+impl IndexedFastqReader {
+    #[new]
+    fn new(fastq_path: &str) -> PyResult<Self> {
+        let path = Path::new(fastq_path).to_path_buf();
+        let index_path = fastq_path.to_string() + ".fqi";
+        let index_path = Path::new(&index_path);
+
+        let index = if index_path.exists() {
+            read_fastq_index(index_path)
+                .map_err(|e| pyo3::exceptions::PyIOError::new_err(format!("Failed to read .fqi index: {}", e)))?
+        } else {
+            let index = build_fastq_index(&path)
+                .map_err(|e| pyo3::exceptions::PyIOError::new_err(format!("Failed to build FASTQ index: {}", e)))?;
+
+            write_fastq_index(index_path, &index)
+                .map_err(|e| pyo3::exceptions::PyIOError::new_err(format!("Failed to write .fqi index: {}", e)))?;
+
+            index
+        };
+
+        let by_name = index
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| (entry.name.clone(), i))
+            .collect();
+
+        Ok(IndexedFastqReader { path, index, by_name })
+    }
+
+    /// Look up a single read by name, returning its (sequence, quality) strings.
+    fn query(&self, name: &str) -> PyResult<(String, String)> {
+        let &i = self
+            .by_name
+            .get(name)
+            .ok_or_else(|| pyo3::exceptions::PyKeyError::new_err(format!("Unknown read name '{}'", name)))?;
+
+        self.read_record_at(self.index[i].offset)
+            .map_err(|e| pyo3::exceptions::PyIOError::new_err(format!("Failed to read record '{}': {}", name, e)))
+    }
+
+    /// List the indexed records, useful for building bounds or sampling in Python land.
+    fn records(&self) -> Vec<PyFastqRecord> {
+        self.index.iter().map(PyFastqRecord::from).collect()
+    }
+}
+
+impl IndexedFastqReader {
+    fn read_record_at(&self, offset: u64) -> io::Result<(String, String)> {
+        let mut file = File::open(&self.path)?;
+        file.seek(io::SeekFrom::Start(offset))?;
+
+        let mut reader = fastq::io::Reader::new(io::BufReader::new(file));
+        let mut record = fastq::Record::default();
+
+        // A truncated final record surfaces as an `UnexpectedEof` here rather than
+        // silently reading past the end of the file.
+        let bytes_read = reader.read_record(&mut record)?;
+        if bytes_read == 0 {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated FASTQ record"));
+        }
+
+        Ok((
+            String::from_utf8_lossy(record.sequence()).to_string(),
+            String::from_utf8_lossy(record.quality_scores()).to_string(),
+        ))
+    }
+}
 
 #[pymodule]
 fn noodles_fasta_wrapper(_: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<IndexedMmapFastaReader>()?;
+    m.add_class::<VariantOverlayFastaReader>()?;
+    m.add_class::<IndexedFastqReader>()?;
     Ok(())
 }
 